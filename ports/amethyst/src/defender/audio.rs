@@ -0,0 +1,71 @@
+use std::iter::Cycle;
+use std::vec::IntoIter;
+
+use amethyst::assets::{AssetStorage, Loader};
+use amethyst::audio::output::Output;
+use amethyst::audio::{AudioSink, OggFormat, Source, SourceHandle};
+use amethyst::prelude::*;
+
+use crate::defender::config::AudioConfig;
+
+const LASER_SOUND: &str = "resources/audio/laser.ogg";
+const EXPLOSION_SOUND: &str = "resources/audio/explosion.ogg";
+const THEME_SOUND: &str = "resources/audio/theme.ogg";
+
+pub struct Sounds {
+    pub laser_sfx: SourceHandle,
+    pub explosion_sfx: SourceHandle,
+}
+
+/// Looping iterator over the background tracks; consumed by `amethyst::audio::DjSystem`
+/// to keep the theme playing for as long as the gameplay state is active.
+pub struct Music {
+    pub music: Cycle<IntoIter<SourceHandle>>,
+}
+
+fn load_audio_track(loader: &Loader, world: &World, file: &str) -> SourceHandle {
+    loader.load(file, OggFormat, (), (), &world.read_resource())
+}
+
+pub fn initialize_audio(world: &mut World) {
+    let (sounds, music) = {
+        let loader = world.read_resource::<Loader>();
+
+        let music = Music {
+            music: vec![load_audio_track(&loader, world, THEME_SOUND)].into_iter().cycle(),
+        };
+
+        let sounds = Sounds {
+            laser_sfx: load_audio_track(&loader, world, LASER_SOUND),
+            explosion_sfx: load_audio_track(&loader, world, EXPLOSION_SOUND),
+        };
+
+        (sounds, music)
+    };
+
+    let config = world.read_resource::<AudioConfig>().clone();
+    let mut sink = world.write_resource::<AudioSink>();
+    sink.set_volume(if config.muted { 0.0 } else { config.music_volume });
+    drop(sink);
+
+    world.add_resource(sounds);
+    world.add_resource(music);
+}
+
+pub fn play_laser(sounds: &Sounds, storage: &AssetStorage<Source>, output: Option<&Output>, config: &AudioConfig) {
+    play(sounds.laser_sfx.clone(), storage, output, config);
+}
+
+pub fn play_explosion(sounds: &Sounds, storage: &AssetStorage<Source>, output: Option<&Output>, config: &AudioConfig) {
+    play(sounds.explosion_sfx.clone(), storage, output, config);
+}
+
+fn play(handle: SourceHandle, storage: &AssetStorage<Source>, output: Option<&Output>, config: &AudioConfig) {
+    if config.muted {
+        return;
+    }
+
+    if let (Some(output), Some(sound)) = (output, storage.get(&handle)) {
+        output.play_once(sound, config.sfx_volume);
+    }
+}
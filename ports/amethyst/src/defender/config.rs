@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+
+pub mod consts {
+    pub const WIN_WIDTH: f32 = 800.0;
+    pub const WIN_HEIGHT: f32 = 600.0;
+    pub const FRAC_WIN_WIDTH_2: f32 = WIN_WIDTH / 2.0;
+    pub const FRAC_WIN_HEIGHT_2: f32 = WIN_HEIGHT / 2.0;
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct GameConfig {
+    pub enemy_count: u32,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig { enemy_count: 10 }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PlayerConfig {
+    pub dimensions: [f32; 2],
+    pub color: [f32; 4],
+    pub speed: f32,
+}
+
+impl Default for PlayerConfig {
+    fn default() -> Self {
+        PlayerConfig {
+            dimensions: [20.0, 20.0],
+            color: [1.0, 1.0, 1.0, 1.0],
+            speed: 150.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct EnemyConfig {
+    pub dimensions: [f32; 2],
+    pub speed: f32,
+}
+
+impl Default for EnemyConfig {
+    fn default() -> Self {
+        EnemyConfig {
+            dimensions: [20.0, 20.0],
+            speed: 50.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct BulletConfig {
+    pub dimensions: [f32; 2],
+    pub color: [f32; 4],
+    pub speed: f32,
+}
+
+impl Default for BulletConfig {
+    fn default() -> Self {
+        BulletConfig {
+            dimensions: [5.0, 10.0],
+            color: [1.0, 1.0, 0.0, 1.0],
+            speed: 300.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ExplosionConfig {
+    pub particle_count: u32,
+    pub particle_dimensions: [f32; 2],
+    pub color: [f32; 4],
+    pub min_speed: f32,
+    pub max_speed: f32,
+    pub lifetime: f32,
+}
+
+impl Default for ExplosionConfig {
+    fn default() -> Self {
+        ExplosionConfig {
+            particle_count: 16,
+            particle_dimensions: [4.0, 4.0],
+            color: [1.0, 0.6, 0.1, 1.0],
+            min_speed: 40.0,
+            max_speed: 120.0,
+            lifetime: 0.6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AudioConfig {
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        AudioConfig {
+            music_volume: 0.3,
+            sfx_volume: 0.5,
+            muted: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WorldConfig {
+    pub width: f32,
+    pub height: f32,
+    pub scroll_margin: f32,
+}
+
+impl Default for WorldConfig {
+    fn default() -> Self {
+        WorldConfig {
+            width: 3200.0,
+            height: consts::WIN_HEIGHT,
+            scroll_margin: 150.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StarfieldLayerConfig {
+    pub speed: f32,
+    pub color: [f32; 4],
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct StarfieldConfig {
+    pub stars_per_layer: u32,
+    pub layers: Vec<StarfieldLayerConfig>,
+}
+
+impl Default for StarfieldConfig {
+    fn default() -> Self {
+        StarfieldConfig {
+            stars_per_layer: 60,
+            layers: vec![
+                StarfieldLayerConfig { speed: 0.1, color: [0.5, 0.5, 0.6, 1.0] },
+                StarfieldLayerConfig { speed: 0.3, color: [0.75, 0.75, 0.85, 1.0] },
+                StarfieldLayerConfig { speed: 0.6, color: [1.0, 1.0, 1.0, 1.0] },
+            ],
+        }
+    }
+}
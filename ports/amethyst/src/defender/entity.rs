@@ -0,0 +1,63 @@
+use amethyst::assets::Handle;
+use amethyst::ecs::{Component, DenseVecStorage, Entity};
+use amethyst::renderer::{Material, MeshHandle};
+
+#[derive(Clone)]
+pub struct BulletResource {
+    pub mesh: MeshHandle,
+    pub material: Handle<Material>,
+}
+
+pub struct Bullet {
+    pub dx: f32,
+    pub dy: f32,
+}
+
+impl Component for Bullet {
+    type Storage = DenseVecStorage<Self>;
+}
+
+#[derive(Clone)]
+pub struct EnemyResource {
+    pub mesh: MeshHandle,
+    pub material: Handle<Material>,
+}
+
+#[derive(Default)]
+pub struct Enemy;
+
+impl Component for Enemy {
+    type Storage = DenseVecStorage<Self>;
+}
+
+pub struct Player {
+    pub direction: f32,
+    pub weapon_cooldown: f32,
+}
+
+impl Component for Player {
+    type Storage = DenseVecStorage<Self>;
+}
+
+pub struct ScoreText {
+    pub text: Entity,
+}
+
+pub struct Particle {
+    pub velocity: [f32; 2],
+    pub lifetime: f32,
+    pub max_lifetime: f32,
+}
+
+impl Component for Particle {
+    type Storage = DenseVecStorage<Self>;
+}
+
+pub struct Star {
+    pub layer: usize,
+    pub base_x: f32,
+}
+
+impl Component for Star {
+    type Storage = DenseVecStorage<Self>;
+}
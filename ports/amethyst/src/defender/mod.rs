@@ -1,8 +1,9 @@
-use amethyst::assets::Loader;
 use amethyst::core::transform::Transform;
+use amethyst::ecs::Entity;
 use amethyst::prelude::*;
 use amethyst::renderer::{
     Camera,
+    ElementState,
     Event,
     KeyboardInput,
     Projection,
@@ -11,24 +12,22 @@ use amethyst::renderer::{
 };
 use amethyst::ui::{
     Anchor,
-    TtfFormat,
     UiText,
     UiTransform
 };
 use rand::prelude::*;
 
+mod audio;
+use audio::initialize_audio;
+
 pub mod config;
 use config::{
     consts::{
         FRAC_WIN_HEIGHT_2,
         FRAC_WIN_WIDTH_2,
-        WIN_HEIGHT,
-        WIN_WIDTH,
     },
-    BulletConfig,
-    EnemyConfig,
     GameConfig,
-    PlayerConfig,
+    WorldConfig,
 };
 
 mod entity;
@@ -36,37 +35,72 @@ use entity::{
     Bullet,
     BulletResource,
     Enemy,
-    EnemyResource,
+    Particle,
     Player,
     ScoreText
 };
 
+mod mouse;
+use mouse::Mouse;
+
 mod render;
-use render::{
-    create_mesh,
-    create_material,
-    generate_rectangle_vertices,
-    generate_triangle_vertices,
-};
+
+pub mod resources;
+use resources::Resources;
+
+mod starfield;
+use starfield::initialize_starfield;
+
+pub mod states;
+use states::PausedState;
+use states::GameOverState;
 
 pub mod systems;
 
-pub struct Defender;
+mod weapons;
+
+#[derive(Default)]
+pub struct Defender {
+    entities: Vec<Entity>,
+}
 
 impl SimpleState for Defender {
     fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
         let world = data.world;
 
         // Initialize entities that exist at the beginning.
-        initialize_camera(world);
-        initialize_enemies(world);
-        initialize_player(world);
+        self.entities.push(initialize_camera(world));
+        self.entities.extend(initialize_starfield(world));
+        self.entities.extend(initialize_enemies(world));
+        self.entities.push(initialize_player(world));
         // Initialize resources
         initialize_bullet(world);
-        initialize_score(world);
+        self.entities.push(initialize_score(world));
+        world.register::<Particle>();
+        initialize_audio(world);
+        world.add_resource(Mouse::default());
     }
 
-    fn handle_event(&mut self, _: StateData<'_, GameData<'_, '_>>, event: StateEvent) -> SimpleTrans {
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+        for entity in self.entities.drain(..) {
+            let _ = world.delete_entity(entity);
+        }
+    }
+
+    fn update(&mut self, data: StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
+        data.data.update(&data.world);
+        data.world.write_resource::<Mouse>().clear_edges();
+
+        if !world_has_player(&data.world) {
+            let score = final_score(&data.world);
+            return Trans::Switch(Box::new(GameOverState::new(score)));
+        }
+
+        Trans::None
+    }
+
+    fn handle_event(&mut self, data: StateData<'_, GameData<'_, '_>>, event: StateEvent) -> SimpleTrans {
         if let StateEvent::Window(event) = &event {
             match event {
                 Event::WindowEvent { event, .. } => {
@@ -77,8 +111,32 @@ impl SimpleState for Defender {
                                 ..
                             },
                             ..
-                        } => Trans::Quit,
+                        } => Trans::Push(Box::new(PausedState::default())),
+                        WindowEvent::KeyboardInput {
+                            input: KeyboardInput {
+                                virtual_keycode: Some(VirtualKeyCode::R),
+                                ..
+                            },
+                            ..
+                        } => {
+                            self.restart(data.world);
+                            Trans::None
+                        },
                         WindowEvent::CloseRequested => Trans::Quit,
+                        WindowEvent::CursorMoved { position, .. } => {
+                            let camera_x = camera_x(&data.world);
+                            let (x, y) = mouse::screen_to_world(position.x as f32, position.y as f32, camera_x);
+                            data.world.write_resource::<Mouse>().set_position(x, y);
+                            Trans::None
+                        },
+                        WindowEvent::MouseInput { state, button, .. } => {
+                            let mut mouse = data.world.write_resource::<Mouse>();
+                            match state {
+                                ElementState::Pressed => mouse.set_button_down(*button),
+                                ElementState::Released => mouse.set_button_up(*button),
+                            }
+                            Trans::None
+                        },
                         _ => Trans::None,
                     }
                 },
@@ -90,31 +148,102 @@ impl SimpleState for Defender {
     }
 }
 
-fn initialize_bullet(world: &mut World) {
-    let (dimensions, color) = {
-        let config = &world.read_resource::<BulletConfig>();
-        (config.dimensions, config.color)
+impl Defender {
+    /// Resets the current run in place: clears enemies, bullets, and
+    /// particles, puts the player back at its spawn point, zeroes the score, and
+    /// spawns a fresh wave — without tearing down the state (and its camera,
+    /// starfield, and audio) the way a full `on_start`/`on_stop` cycle would.
+    fn restart(&mut self, world: &mut World) {
+        clear_run_entities(world);
+        reset_player(world);
+        reset_score(world);
+
+        self.entities.extend(initialize_enemies(world));
+    }
+}
+
+fn clear_run_entities(world: &mut World) {
+    use amethyst::ecs::Join;
+
+    let dead: Vec<Entity> = {
+        let entities = world.entities();
+        let enemies = world.read_storage::<Enemy>();
+        let bullets = world.read_storage::<Bullet>();
+        let particles = world.read_storage::<Particle>();
+
+        (&entities, (&enemies).maybe(), (&bullets).maybe(), (&particles).maybe())
+            .join()
+            .filter(|(_, enemy, bullet, particle)| enemy.is_some() || bullet.is_some() || particle.is_some())
+            .map(|(entity, _, _, _)| entity)
+            .collect()
     };
 
-    let bullet_mesh = create_mesh(
-        world,
-        generate_rectangle_vertices(0.0, 0.0, dimensions[0], dimensions[1])
-    );
+    for entity in dead {
+        let _ = world.delete_entity(entity);
+    }
+}
+
+fn reset_player(world: &mut World) {
+    use amethyst::ecs::Join;
+
+    let mut transforms = world.write_storage::<Transform>();
+    let mut players = world.write_storage::<Player>();
+
+    for (transform, player) in (&mut transforms, &mut players).join() {
+        transform.set_xyz(FRAC_WIN_WIDTH_2, 0.0, 0.0);
+        player.direction = 0.0;
+        player.weapon_cooldown = 0.0;
+    }
+}
+
+fn reset_score(world: &mut World) {
+    let score_text = world.read_resource::<ScoreText>().text;
+    if let Some(text) = world.write_storage::<UiText>().get_mut(score_text) {
+        text.text = "Score: 00000".to_string();
+    }
+}
+
+fn world_has_player(world: &World) -> bool {
+    use amethyst::ecs::Join;
+
+    world.read_storage::<Player>().join().next().is_some()
+}
+
+/// Reads the score text before the dying `Defender` state's `on_stop` tears
+/// down the `ScoreText` entity, so `GameOverState` has something to show.
+fn final_score(world: &World) -> String {
+    let score_text = world.read_resource::<ScoreText>();
+    let ui_text = world.read_storage::<UiText>();
+    ui_text.get(score_text.text)
+        .map(|text| text.text.clone())
+        .unwrap_or_else(|| "Score: 00000".to_string())
+}
 
-    let bullet_material = create_material(world, color);
+fn camera_x(world: &World) -> f32 {
+    use amethyst::ecs::Join;
+
+    (&world.read_storage::<Camera>(), &world.read_storage::<Transform>())
+        .join()
+        .map(|(_, transform)| transform.translation().x)
+        .next()
+        .unwrap_or(0.0)
+}
+
+fn initialize_bullet(world: &mut World) {
+    let resources = world.read_resource::<Resources>().clone();
     let bullet_resource = BulletResource {
-        material: bullet_material,
-        mesh: bullet_mesh
+        material: resources.bullet_material,
+        mesh: resources.bullet_mesh,
     };
 
     // Register bullet entity & add resource so we can use it later.
     world.register::<Bullet>();
-    world.add_resource(bullet_resource.clone());
+    world.add_resource(bullet_resource);
 }
 
-fn initialize_camera(world: &mut World) {
+fn initialize_camera(world: &mut World) -> Entity {
     let mut transform = Transform::default();
-    transform.set_z(1.0);
+    transform.set_xyz(FRAC_WIN_WIDTH_2, 0.0, 1.0);
 
     world.create_entity()
         .with(Camera::from(Projection::orthographic(
@@ -124,15 +253,15 @@ fn initialize_camera(world: &mut World) {
             FRAC_WIN_HEIGHT_2,
         )))
         .with(transform)
-        .build();
+        .build()
 }
 
-fn initialize_enemies(world: &mut World) {
+fn initialize_enemies(world: &mut World) -> Vec<Entity> {
     let mut rng = rand::thread_rng();
 
-    let dimensions = {
-        let config = &world.read_resource::<EnemyConfig>();
-        config.dimensions
+    let (mesh, material) = {
+        let resources = world.read_resource::<Resources>();
+        (resources.enemy_mesh.clone(), resources.enemy_material.clone())
     };
 
     let num_enemies = {
@@ -140,52 +269,44 @@ fn initialize_enemies(world: &mut World) {
         config.enemy_count
     };
 
-    let mesh = create_mesh(
-        world,
-        generate_rectangle_vertices(0.0, 0.0, dimensions[0], dimensions[1])
-    );
-
-    let material = create_material(world, [1.0, 0.0, 0.0, 1.0]);
-    // let resource = EnemyResource { material, mesh };
+    let world_config = world.read_resource::<WorldConfig>().clone();
 
     world.register::<Enemy>();
+    let mut entities = Vec::with_capacity(num_enemies as usize);
     for _ in 0..num_enemies {
         let mut transform = Transform::default();
-        let x = (rng.gen::<f32>() * WIN_WIDTH - FRAC_WIN_WIDTH_2)
-            .min(FRAC_WIN_WIDTH_2)
-            .max(-FRAC_WIN_WIDTH_2);
+        // Spread enemies across the whole scrolling world, not just the
+        // window's initial viewport.
+        let x = rng.gen::<f32>() * world_config.width;
 
-        let y: f32 = (rng.gen::<f32>() * WIN_HEIGHT - FRAC_WIN_HEIGHT_2)
+        let y: f32 = (rng.gen::<f32>() * world_config.height - FRAC_WIN_HEIGHT_2)
             .min(FRAC_WIN_HEIGHT_2)
             .max(-FRAC_WIN_HEIGHT_2);
 
         transform.set_xyz(x, y, 0.0);
 
-        world.create_entity()
+        let enemy = world.create_entity()
             .with(mesh.clone())
             .with(material.clone())
             .with(Enemy::default())
             .with(transform)
             .build();
+
+        entities.push(enemy);
     }
+
+    entities
 }
 
-fn initialize_player(world: &mut World) {
+fn initialize_player(world: &mut World) -> Entity {
     let mut player_transform = Transform::default();
-    player_transform.set_xyz(0.0, 0.0, 0.0);
+    player_transform.set_xyz(FRAC_WIN_WIDTH_2, 0.0, 0.0);
 
-    let (dimensions, color) = {
-        let config = &world.read_resource::<PlayerConfig>();
-        (config.dimensions, config.color)
+    let (player_mesh, player_material) = {
+        let resources = world.read_resource::<Resources>();
+        (resources.player_mesh.clone(), resources.player_material.clone())
     };
 
-    let player_mesh = create_mesh(
-        world,
-        generate_triangle_vertices(0.0, 0.0, dimensions[0], dimensions[1])
-    );
-
-    let player_material = create_material(world, color);
-
     // Create player triangle
     world.create_entity()
         .with(player_mesh)
@@ -195,17 +316,11 @@ fn initialize_player(world: &mut World) {
             weapon_cooldown: 0.0
         })
         .with(player_transform)
-        .build();
+        .build()
 }
 
-fn initialize_score(world: &mut World) {
-    let font = world.read_resource::<Loader>().load(
-        "resources/fonts/PxPlus_IBM_VGA8.ttf",
-        TtfFormat,
-        Default::default(),
-        (),
-        &world.read_resource(),
-    );
+fn initialize_score(world: &mut World) -> Entity {
+    let font = world.read_resource::<Resources>().font.clone();
 
     let transform = UiTransform::new(
         "Score".to_string(),
@@ -221,11 +336,13 @@ fn initialize_score(world: &mut World) {
     let text = world.create_entity()
         .with(transform)
         .with(UiText::new(
-            font.clone(),
+            font,
             "Score: 00000".to_string(),
             [1., 1., 1., 1.],
             25.,
         )).build();
 
     world.add_resource(ScoreText { text } );
-}
\ No newline at end of file
+
+    text
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use amethyst::renderer::MouseButton;
+
+use crate::defender::config::consts::{FRAC_WIN_WIDTH_2, WIN_HEIGHT};
+
+/// Edge-triggered mouse state, refreshed from `WindowEvent`s in the gameplay
+/// state's `handle_event` and cleared once per frame in `update`. Lets
+/// systems tell "button held" from "button pressed this frame" apart, which
+/// `InputHandler` alone cannot do for us.
+#[derive(Default)]
+pub struct Mouse {
+    pub position: (f32, f32),
+    down: HashMap<MouseButton, bool>,
+    pressed: HashMap<MouseButton, bool>,
+    released: HashMap<MouseButton, bool>,
+}
+
+impl Mouse {
+    pub fn is_down(&self, button: MouseButton) -> bool {
+        *self.down.get(&button).unwrap_or(&false)
+    }
+
+    pub fn was_pressed(&self, button: MouseButton) -> bool {
+        *self.pressed.get(&button).unwrap_or(&false)
+    }
+
+    pub fn was_released(&self, button: MouseButton) -> bool {
+        *self.released.get(&button).unwrap_or(&false)
+    }
+
+    pub fn set_button_down(&mut self, button: MouseButton) {
+        if !self.is_down(button) {
+            self.pressed.insert(button, true);
+        }
+        self.down.insert(button, true);
+    }
+
+    pub fn set_button_up(&mut self, button: MouseButton) {
+        if self.is_down(button) {
+            self.released.insert(button, true);
+        }
+        self.down.insert(button, false);
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.position = (x, y);
+    }
+
+    pub fn clear_edges(&mut self) {
+        self.pressed.clear();
+        self.released.clear();
+    }
+}
+
+/// Projects a window cursor position (origin top-left, y-down) into world
+/// coordinates relative to the camera (origin at screen center, y-up), then
+/// adds `camera_x`, the camera's own world-space x, to account for scrolling.
+pub fn screen_to_world(x: f32, y: f32, camera_x: f32) -> (f32, f32) {
+    (camera_x + x - FRAC_WIN_WIDTH_2, WIN_HEIGHT / 2.0 - y)
+}
@@ -0,0 +1,58 @@
+use amethyst::assets::{Handle, Loader};
+use amethyst::prelude::*;
+use amethyst::renderer::{
+    Material,
+    MaterialDefaults,
+    MeshData,
+    MeshHandle,
+    PosColor,
+};
+
+pub fn generate_rectangle_vertices(x: f32, y: f32, width: f32, height: f32) -> Vec<PosColor> {
+    let (min_x, max_x) = (x - width / 2.0, x + width / 2.0);
+    let (min_y, max_y) = (y - height / 2.0, y + height / 2.0);
+    let color = [1.0, 1.0, 1.0, 1.0];
+
+    vec![
+        PosColor { position: [min_x, min_y, 0.0], color },
+        PosColor { position: [max_x, min_y, 0.0], color },
+        PosColor { position: [max_x, max_y, 0.0], color },
+        PosColor { position: [max_x, max_y, 0.0], color },
+        PosColor { position: [min_x, max_y, 0.0], color },
+        PosColor { position: [min_x, min_y, 0.0], color },
+    ]
+}
+
+pub fn generate_triangle_vertices(x: f32, y: f32, width: f32, height: f32) -> Vec<PosColor> {
+    let color = [1.0, 1.0, 1.0, 1.0];
+
+    vec![
+        PosColor { position: [x, y + height / 2.0, 0.0], color },
+        PosColor { position: [x - width / 2.0, y - height / 2.0, 0.0], color },
+        PosColor { position: [x + width / 2.0, y - height / 2.0, 0.0], color },
+    ]
+}
+
+pub fn create_mesh(world: &World, vertices: Vec<PosColor>) -> MeshHandle {
+    let loader = world.read_resource::<Loader>();
+    let mesh_storage = world.read_resource();
+
+    loader.load_from_data(MeshData::from(vertices), (), &mesh_storage)
+}
+
+pub fn create_material(world: &World, color: [f32; 4]) -> Handle<Material> {
+    let mat_defaults = world.read_resource::<MaterialDefaults>().0.clone();
+    let loader = world.read_resource::<Loader>();
+    let texture_storage = world.read_resource();
+
+    let albedo = loader.load_from_data(color.into(), (), &texture_storage);
+
+    loader.load_from_data(
+        Material {
+            albedo,
+            ..mat_defaults
+        },
+        (),
+        &world.read_resource(),
+    )
+}
@@ -0,0 +1,129 @@
+use amethyst::assets::{AssetStorage, FontAsset, Handle, Loader};
+use amethyst::prelude::*;
+use amethyst::renderer::{Material, Mesh, MeshHandle};
+use amethyst::ui::{FontHandle, TtfFormat};
+
+use crate::defender::config::{BulletConfig, EnemyConfig, ExplosionConfig, PlayerConfig};
+use crate::defender::render::{
+    create_material,
+    create_mesh,
+    generate_rectangle_vertices,
+    generate_triangle_vertices,
+};
+
+/// Number of precomputed alpha steps `ExplosionSystem` fades particles
+/// through. Coarse enough to keep the material count small, fine enough
+/// that the fade doesn't visibly step.
+const PARTICLE_FADE_STEPS: usize = 16;
+
+/// Every font, mesh, and material the gameplay state needs, loaded exactly
+/// once by `LoadingState` instead of ad hoc inside each `initialize_*` call.
+#[derive(Clone)]
+pub struct Resources {
+    pub font: FontHandle,
+    pub bullet_mesh: MeshHandle,
+    pub bullet_material: Handle<Material>,
+    pub enemy_mesh: MeshHandle,
+    pub enemy_material: Handle<Material>,
+    pub player_mesh: MeshHandle,
+    pub player_material: Handle<Material>,
+    pub particle_mesh: MeshHandle,
+    pub particle_material: Handle<Material>,
+    /// `particle_fade_materials[i]` is `particle_material` with alpha scaled
+    /// by `i / (PARTICLE_FADE_STEPS - 1)`, so `ExplosionSystem` can fade a
+    /// particle out by picking a bucket instead of allocating a new
+    /// material every frame.
+    pub particle_fade_materials: Vec<Handle<Material>>,
+}
+
+impl Resources {
+    pub fn load(world: &mut World) -> Resources {
+        let font = world.read_resource::<Loader>().load(
+            "resources/fonts/PxPlus_IBM_VGA8.ttf",
+            TtfFormat,
+            Default::default(),
+            (),
+            &world.read_resource(),
+        );
+
+        let (bullet_dimensions, bullet_color) = {
+            let config = &world.read_resource::<BulletConfig>();
+            (config.dimensions, config.color)
+        };
+        let bullet_mesh = create_mesh(
+            world,
+            generate_rectangle_vertices(0.0, 0.0, bullet_dimensions[0], bullet_dimensions[1]),
+        );
+        let bullet_material = create_material(world, bullet_color);
+
+        let enemy_dimensions = world.read_resource::<EnemyConfig>().dimensions;
+        let enemy_mesh = create_mesh(
+            world,
+            generate_rectangle_vertices(0.0, 0.0, enemy_dimensions[0], enemy_dimensions[1]),
+        );
+        let enemy_material = create_material(world, [1.0, 0.0, 0.0, 1.0]);
+
+        let (player_dimensions, player_color) = {
+            let config = &world.read_resource::<PlayerConfig>();
+            (config.dimensions, config.color)
+        };
+        let player_mesh = create_mesh(
+            world,
+            generate_triangle_vertices(0.0, 0.0, player_dimensions[0], player_dimensions[1]),
+        );
+        let player_material = create_material(world, player_color);
+
+        let (particle_dimensions, particle_color) = {
+            let config = &world.read_resource::<ExplosionConfig>();
+            (config.particle_dimensions, config.color)
+        };
+        let particle_mesh = create_mesh(
+            world,
+            generate_rectangle_vertices(0.0, 0.0, particle_dimensions[0], particle_dimensions[1]),
+        );
+        let particle_material = create_material(world, particle_color);
+
+        let particle_fade_materials = (0..PARTICLE_FADE_STEPS)
+            .map(|step| {
+                let alpha = step as f32 / (PARTICLE_FADE_STEPS - 1) as f32;
+                create_material(world, [
+                    particle_color[0],
+                    particle_color[1],
+                    particle_color[2],
+                    particle_color[3] * alpha,
+                ])
+            })
+            .collect();
+
+        Resources {
+            font,
+            bullet_mesh,
+            bullet_material,
+            enemy_mesh,
+            enemy_material,
+            player_mesh,
+            player_material,
+            particle_mesh,
+            particle_material,
+            particle_fade_materials,
+        }
+    }
+
+    /// True once every handle above has finished loading.
+    pub fn is_complete(&self, world: &World) -> bool {
+        let fonts = world.read_resource::<AssetStorage<FontAsset>>();
+        let meshes = world.read_resource::<AssetStorage<Mesh>>();
+        let materials = world.read_resource::<AssetStorage<Material>>();
+
+        fonts.get(&self.font).is_some()
+            && meshes.get(&self.bullet_mesh).is_some()
+            && meshes.get(&self.enemy_mesh).is_some()
+            && meshes.get(&self.player_mesh).is_some()
+            && meshes.get(&self.particle_mesh).is_some()
+            && materials.get(&self.bullet_material).is_some()
+            && materials.get(&self.enemy_material).is_some()
+            && materials.get(&self.player_material).is_some()
+            && materials.get(&self.particle_material).is_some()
+            && self.particle_fade_materials.iter().all(|material| materials.get(material).is_some())
+    }
+}
@@ -0,0 +1,59 @@
+use amethyst::core::transform::Transform;
+use amethyst::ecs::Entity;
+use amethyst::prelude::*;
+use rand::prelude::*;
+
+use crate::defender::config::{StarfieldConfig, WorldConfig};
+use crate::defender::entity::Star;
+use crate::defender::render::{create_material, create_mesh, generate_rectangle_vertices};
+
+pub struct StarfieldLayer {
+    pub speed: f32,
+}
+
+/// The parallax layers behind gameplay; each layer's `speed` is the fraction
+/// of the camera's scroll at which its stars move across the screen, so
+/// layers with a higher `speed` read as nearer, giving the background depth.
+pub struct Starfield {
+    pub layers: Vec<StarfieldLayer>,
+}
+
+pub fn initialize_starfield(world: &mut World) -> Vec<Entity> {
+    let config = world.read_resource::<StarfieldConfig>().clone();
+    let world_config = world.read_resource::<WorldConfig>().clone();
+
+    let mesh = create_mesh(world, generate_rectangle_vertices(0.0, 0.0, 2.0, 2.0));
+    let mut rng = rand::thread_rng();
+
+    world.register::<Star>();
+
+    let mut entities = Vec::new();
+    let mut layers = Vec::with_capacity(config.layers.len());
+
+    for (layer_index, layer_config) in config.layers.iter().enumerate() {
+        let material = create_material(world, layer_config.color);
+
+        for _ in 0..config.stars_per_layer {
+            let base_x = rng.gen::<f32>() * world_config.width;
+            let y = rng.gen::<f32>() * world_config.height - world_config.height / 2.0;
+
+            let mut transform = Transform::default();
+            transform.set_xyz(base_x, y, -1.0 - layer_index as f32 * 0.1);
+
+            let star = world.create_entity()
+                .with(mesh.clone())
+                .with(material.clone())
+                .with(Star { layer: layer_index, base_x })
+                .with(transform)
+                .build();
+
+            entities.push(star);
+        }
+
+        layers.push(StarfieldLayer { speed: layer_config.speed });
+    }
+
+    world.add_resource(Starfield { layers });
+
+    entities
+}
@@ -0,0 +1,113 @@
+use amethyst::ecs::Entity;
+use amethyst::prelude::*;
+use amethyst::renderer::{Event, KeyboardInput, VirtualKeyCode, WindowEvent};
+use amethyst::ui::{Anchor, UiText, UiTransform};
+
+use crate::defender::resources::Resources;
+use crate::defender::Defender;
+
+#[derive(Default)]
+pub struct GameOverState {
+    score: String,
+    entities: Vec<Entity>,
+}
+
+impl GameOverState {
+    /// `score` is the final `ScoreText` string, captured by `Defender` before
+    /// its `on_stop` deletes that entity out from under us.
+    pub fn new(score: String) -> Self {
+        GameOverState { score, entities: Vec::new() }
+    }
+}
+
+impl SimpleState for GameOverState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        let final_score = self.score.clone();
+        let font = world.read_resource::<Resources>().font.clone();
+
+        let title_transform = UiTransform::new(
+            "GameOverTitle".to_string(),
+            Anchor::TopMiddle,
+            0.0, -80.0, 1.0,
+            400.0, 60.0,
+            0,
+        );
+
+        let title = world.create_entity()
+            .with(title_transform)
+            .with(UiText::new(
+                font.clone(),
+                "Game Over".to_string(),
+                [1., 1., 1., 1.],
+                50.,
+            )).build();
+
+        let score_transform = UiTransform::new(
+            "FinalScore".to_string(),
+            Anchor::Middle,
+            0.0, 20.0, 1.0,
+            400.0, 40.0,
+            1,
+        );
+
+        let score = world.create_entity()
+            .with(score_transform)
+            .with(UiText::new(
+                font.clone(),
+                final_score,
+                [1., 1., 1., 1.],
+                25.,
+            )).build();
+
+        let prompt_transform = UiTransform::new(
+            "RestartPrompt".to_string(),
+            Anchor::Middle,
+            0.0, -40.0, 1.0,
+            400.0, 40.0,
+            2,
+        );
+
+        let prompt = world.create_entity()
+            .with(prompt_transform)
+            .with(UiText::new(
+                font,
+                "Press Enter to Restart".to_string(),
+                [1., 1., 1., 1.],
+                20.,
+            )).build();
+
+        self.entities = vec![title, score, prompt];
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+        for entity in self.entities.drain(..) {
+            let _ = world.delete_entity(entity);
+        }
+    }
+
+    fn handle_event(&mut self, _: StateData<'_, GameData<'_, '_>>, event: StateEvent) -> SimpleTrans {
+        if let StateEvent::Window(event) = &event {
+            match event {
+                Event::WindowEvent { event, .. } => {
+                    match event {
+                        WindowEvent::KeyboardInput {
+                            input: KeyboardInput {
+                                virtual_keycode: Some(VirtualKeyCode::Return),
+                                ..
+                            },
+                            ..
+                        } => Trans::Switch(Box::new(Defender::default())),
+                        WindowEvent::CloseRequested => Trans::Quit,
+                        _ => Trans::None,
+                    }
+                },
+                _ => Trans::None,
+            }
+        } else {
+            Trans::None
+        }
+    }
+}
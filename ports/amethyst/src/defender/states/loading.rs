@@ -0,0 +1,69 @@
+use amethyst::ecs::Entity;
+use amethyst::prelude::*;
+use amethyst::renderer::{Event, WindowEvent};
+use amethyst::ui::{Anchor, UiText, UiTransform};
+
+use crate::defender::resources::Resources;
+use crate::defender::states::MainMenuState;
+
+#[derive(Default)]
+pub struct LoadingState {
+    resources: Option<Resources>,
+    entities: Vec<Entity>,
+}
+
+impl SimpleState for LoadingState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        let resources = Resources::load(world);
+        let font = resources.font.clone();
+        self.resources = Some(resources);
+
+        let transform = UiTransform::new(
+            "Loading".to_string(),
+            Anchor::Middle,
+            0.0, 0.0, 1.0,
+            400.0, 40.0,
+            0,
+        );
+
+        let text = world.create_entity()
+            .with(transform)
+            .with(UiText::new(
+                font,
+                "Loading...".to_string(),
+                [1., 1., 1., 1.],
+                30.,
+            )).build();
+
+        self.entities.push(text);
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+        for entity in self.entities.drain(..) {
+            let _ = world.delete_entity(entity);
+        }
+    }
+
+    fn update(&mut self, data: StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
+        data.data.update(&data.world);
+
+        let resources = self.resources.as_ref().expect("LoadingState::on_start always sets resources");
+        if resources.is_complete(&data.world) {
+            data.world.add_resource(resources.clone());
+            return Trans::Switch(Box::new(MainMenuState::default()));
+        }
+
+        Trans::None
+    }
+
+    fn handle_event(&mut self, _: StateData<'_, GameData<'_, '_>>, event: StateEvent) -> SimpleTrans {
+        if let StateEvent::Window(Event::WindowEvent { event: WindowEvent::CloseRequested, .. }) = &event {
+            Trans::Quit
+        } else {
+            Trans::None
+        }
+    }
+}
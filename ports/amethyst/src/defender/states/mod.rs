@@ -0,0 +1,9 @@
+mod main_menu;
+mod paused;
+mod game_over;
+mod loading;
+
+pub use self::main_menu::MainMenuState;
+pub use self::paused::PausedState;
+pub use self::game_over::GameOverState;
+pub use self::loading::LoadingState;
@@ -0,0 +1,82 @@
+use amethyst::ecs::Entity;
+use amethyst::prelude::*;
+use amethyst::renderer::{Event, KeyboardInput, VirtualKeyCode, WindowEvent};
+use amethyst::ui::{Anchor, UiImage, UiText, UiTransform};
+
+use crate::defender::config::consts::{WIN_HEIGHT, WIN_WIDTH};
+use crate::defender::resources::Resources;
+
+#[derive(Default)]
+pub struct PausedState {
+    entities: Vec<Entity>,
+}
+
+impl SimpleState for PausedState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        let dim_transform = UiTransform::new(
+            "PauseDim".to_string(),
+            Anchor::Middle,
+            0.0, 0.0, 2.0,
+            WIN_WIDTH, WIN_HEIGHT,
+            0,
+        );
+
+        let dim = world.create_entity()
+            .with(dim_transform)
+            .with(UiImage::SolidColor([0.0, 0.0, 0.0, 0.6]))
+            .build();
+
+        let font = world.read_resource::<Resources>().font.clone();
+
+        let label_transform = UiTransform::new(
+            "PausedLabel".to_string(),
+            Anchor::Middle,
+            0.0, 0.0, 3.0,
+            400.0, 60.0,
+            1,
+        );
+
+        let label = world.create_entity()
+            .with(label_transform)
+            .with(UiText::new(
+                font,
+                "Paused".to_string(),
+                [1., 1., 1., 1.],
+                40.,
+            )).build();
+
+        self.entities = vec![dim, label];
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+        for entity in self.entities.drain(..) {
+            let _ = world.delete_entity(entity);
+        }
+    }
+
+    fn handle_event(&mut self, _: StateData<'_, GameData<'_, '_>>, event: StateEvent) -> SimpleTrans {
+        if let StateEvent::Window(event) = &event {
+            match event {
+                Event::WindowEvent { event, .. } => {
+                    match event {
+                        WindowEvent::KeyboardInput {
+                            input: KeyboardInput {
+                                virtual_keycode: Some(VirtualKeyCode::Escape),
+                                ..
+                            },
+                            ..
+                        } => Trans::Pop,
+                        WindowEvent::CloseRequested => Trans::Quit,
+                        _ => Trans::None,
+                    }
+                },
+                _ => Trans::None,
+            }
+        } else {
+            Trans::None
+        }
+    }
+}
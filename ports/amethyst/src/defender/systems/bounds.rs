@@ -0,0 +1,39 @@
+use amethyst::core::transform::Transform;
+use amethyst::ecs::{Join, ReadExpect, ReadStorage, System, WriteStorage};
+
+use crate::defender::config::WorldConfig;
+use crate::defender::entity::{Bullet, Enemy};
+
+/// Wraps enemies and bullets around the left and right edges of the
+/// scrolling world instead of letting them drift off past the window bounds.
+/// Only x is wrapped; vertical positions are left as they are.
+pub struct WorldBoundsSystem;
+
+impl<'s> System<'s> for WorldBoundsSystem {
+    type SystemData = (
+        WriteStorage<'s, Transform>,
+        ReadStorage<'s, Enemy>,
+        ReadStorage<'s, Bullet>,
+        ReadExpect<'s, WorldConfig>,
+    );
+
+    fn run(&mut self, (mut transforms, enemies, bullets, world_config): Self::SystemData) {
+        for (transform, _) in (&mut transforms, &enemies).join() {
+            wrap_x(transform, world_config.width);
+        }
+
+        for (transform, _) in (&mut transforms, &bullets).join() {
+            wrap_x(transform, world_config.width);
+        }
+    }
+}
+
+fn wrap_x(transform: &mut Transform, world_width: f32) {
+    let x = transform.translation().x;
+
+    if x < 0.0 {
+        transform.set_x(x + world_width);
+    } else if x > world_width {
+        transform.set_x(x - world_width);
+    }
+}
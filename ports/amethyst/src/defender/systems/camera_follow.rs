@@ -0,0 +1,45 @@
+use amethyst::core::transform::Transform;
+use amethyst::ecs::{Join, ReadExpect, ReadStorage, System, WriteStorage};
+use amethyst::renderer::Camera;
+
+use crate::defender::config::consts::FRAC_WIN_WIDTH_2;
+use crate::defender::config::WorldConfig;
+use crate::defender::entity::Player;
+
+pub struct CameraFollowSystem;
+
+impl<'s> System<'s> for CameraFollowSystem {
+    type SystemData = (
+        ReadStorage<'s, Camera>,
+        ReadStorage<'s, Player>,
+        WriteStorage<'s, Transform>,
+        ReadExpect<'s, WorldConfig>,
+    );
+
+    fn run(&mut self, (cameras, players, mut transforms, world_config): Self::SystemData) {
+        let player_x = (&players, &transforms)
+            .join()
+            .map(|(_, transform)| transform.translation().x)
+            .next();
+
+        let player_x = match player_x {
+            Some(x) => x,
+            None => return,
+        };
+
+        let min_x = FRAC_WIN_WIDTH_2;
+        let max_x = (world_config.width - FRAC_WIN_WIDTH_2).max(min_x);
+
+        for (_, transform) in (&cameras, &mut transforms).join() {
+            let camera_x = transform.translation().x;
+            let offset = player_x - camera_x;
+
+            if offset.abs() <= world_config.scroll_margin {
+                continue;
+            }
+
+            let target = player_x - offset.signum() * world_config.scroll_margin;
+            transform.set_x(target.min(max_x).max(min_x));
+        }
+    }
+}
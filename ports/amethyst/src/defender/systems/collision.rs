@@ -0,0 +1,87 @@
+use amethyst::assets::AssetStorage;
+use amethyst::audio::output::Output;
+use amethyst::audio::Source;
+use amethyst::core::transform::Transform;
+use amethyst::ecs::{Entities, Join, LazyUpdate, Read, ReadExpect, ReadStorage, System};
+use rand::prelude::*;
+
+use crate::defender::audio::{play_explosion, Sounds};
+use crate::defender::config::{AudioConfig, ExplosionConfig};
+use crate::defender::entity::{Bullet, Enemy, Particle};
+use crate::defender::resources::Resources;
+
+const HIT_RADIUS: f32 = 16.0;
+
+pub struct CollisionSystem;
+
+impl<'s> System<'s> for CollisionSystem {
+    type SystemData = (
+        Entities<'s>,
+        ReadStorage<'s, Transform>,
+        ReadStorage<'s, Bullet>,
+        ReadStorage<'s, Enemy>,
+        ReadExpect<'s, Resources>,
+        ReadExpect<'s, ExplosionConfig>,
+        Read<'s, LazyUpdate>,
+        ReadExpect<'s, Sounds>,
+        Read<'s, AssetStorage<Source>>,
+        Option<Read<'s, Output>>,
+        ReadExpect<'s, AudioConfig>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, transforms, bullets, enemies, resources, explosion_config, lazy, sounds, sound_storage, audio_output, audio_config): Self::SystemData,
+    ) {
+        let mut rng = rand::thread_rng();
+
+        for (bullet_entity, bullet_transform, _) in (&entities, &transforms, &bullets).join() {
+            for (enemy_entity, enemy_transform, _) in (&entities, &transforms, &enemies).join() {
+                let dx = bullet_transform.translation().x - enemy_transform.translation().x;
+                let dy = bullet_transform.translation().y - enemy_transform.translation().y;
+
+                if (dx * dx + dy * dy).sqrt() < HIT_RADIUS {
+                    spawn_explosion(
+                        &entities,
+                        &resources,
+                        &explosion_config,
+                        &lazy,
+                        &mut rng,
+                        enemy_transform,
+                    );
+                    play_explosion(&sounds, &sound_storage, audio_output.as_ref().map(|o| &**o), &audio_config);
+
+                    let _ = entities.delete(bullet_entity);
+                    let _ = entities.delete(enemy_entity);
+                }
+            }
+        }
+    }
+}
+
+fn spawn_explosion(
+    entities: &Entities,
+    resources: &Resources,
+    config: &ExplosionConfig,
+    lazy: &LazyUpdate,
+    rng: &mut ThreadRng,
+    origin: &Transform,
+) {
+    for _ in 0..config.particle_count {
+        let theta = rng.gen::<f32>() * std::f32::consts::PI * 2.0;
+        let speed = rng.gen_range(config.min_speed, config.max_speed);
+
+        let mut transform = Transform::default();
+        transform.set_xyz(origin.translation().x, origin.translation().y, 0.0);
+
+        let particle_entity = entities.create();
+        lazy.insert(particle_entity, resources.particle_mesh.clone());
+        lazy.insert(particle_entity, resources.particle_material.clone());
+        lazy.insert(particle_entity, transform);
+        lazy.insert(particle_entity, Particle {
+            velocity: [theta.cos() * speed, theta.sin() * speed],
+            lifetime: config.lifetime,
+            max_lifetime: config.lifetime,
+        });
+    }
+}
@@ -0,0 +1,47 @@
+use amethyst::assets::Handle;
+use amethyst::core::timing::Time;
+use amethyst::core::transform::Transform;
+use amethyst::ecs::{Entities, Join, Read, ReadExpect, System, WriteStorage};
+use amethyst::renderer::Material;
+
+use crate::defender::entity::Particle;
+use crate::defender::resources::Resources;
+
+pub struct ExplosionSystem;
+
+impl<'s> System<'s> for ExplosionSystem {
+    type SystemData = (
+        Entities<'s>,
+        WriteStorage<'s, Transform>,
+        WriteStorage<'s, Particle>,
+        WriteStorage<'s, Handle<Material>>,
+        ReadExpect<'s, Resources>,
+        Read<'s, Time>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, mut transforms, mut particles, mut materials, resources, time): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds();
+        let fade_steps = resources.particle_fade_materials.len();
+
+        for (entity, transform, particle) in (&entities, &mut transforms, &mut particles).join() {
+            particle.lifetime -= dt;
+
+            if particle.lifetime <= 0.0 {
+                let _ = entities.delete(entity);
+                continue;
+            }
+
+            transform.translate_x(particle.velocity[0] * dt);
+            transform.translate_y(particle.velocity[1] * dt);
+
+            let remaining = (particle.lifetime / particle.max_lifetime).max(0.0);
+            transform.set_scale(remaining, remaining, 1.0);
+
+            let bucket = ((remaining * (fade_steps - 1) as f32).round() as usize).min(fade_steps - 1);
+            materials.insert(entity, resources.particle_fade_materials[bucket].clone()).ok();
+        }
+    }
+}
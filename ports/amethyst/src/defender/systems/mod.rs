@@ -0,0 +1,17 @@
+mod movement;
+mod shooting;
+mod collision;
+mod explosion;
+mod mouse_aim;
+mod camera_follow;
+mod bounds;
+mod starfield;
+
+pub use self::movement::MovementSystem;
+pub use self::shooting::ShootingSystem;
+pub use self::collision::CollisionSystem;
+pub use self::explosion::ExplosionSystem;
+pub use self::mouse_aim::MouseAimSystem;
+pub use self::camera_follow::CameraFollowSystem;
+pub use self::bounds::WorldBoundsSystem;
+pub use self::starfield::StarfieldSystem;
@@ -0,0 +1,59 @@
+use amethyst::assets::AssetStorage;
+use amethyst::audio::output::Output;
+use amethyst::audio::Source;
+use amethyst::core::transform::Transform;
+use amethyst::ecs::{Entities, Join, LazyUpdate, Read, ReadExpect, ReadStorage, System, WriteStorage};
+use amethyst::renderer::MouseButton;
+
+use crate::defender::audio::Sounds;
+use crate::defender::config::AudioConfig;
+use crate::defender::entity::{BulletResource, Player};
+use crate::defender::mouse::Mouse;
+use crate::defender::weapons::fire_bullet;
+
+pub struct MouseAimSystem;
+
+impl<'s> System<'s> for MouseAimSystem {
+    type SystemData = (
+        Entities<'s>,
+        ReadStorage<'s, Transform>,
+        WriteStorage<'s, Player>,
+        ReadExpect<'s, Mouse>,
+        ReadExpect<'s, BulletResource>,
+        Read<'s, LazyUpdate>,
+        ReadExpect<'s, Sounds>,
+        Read<'s, AssetStorage<Source>>,
+        Option<Read<'s, Output>>,
+        ReadExpect<'s, AudioConfig>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, transforms, mut players, mouse, bullet_resource, lazy, sounds, sound_storage, audio_output, audio_config): Self::SystemData,
+    ) {
+        let (mouse_x, mouse_y) = mouse.position;
+        let fire = mouse.was_pressed(MouseButton::Left);
+
+        for (transform, player) in (&transforms, &mut players).join() {
+            let dx = mouse_x - transform.translation().x;
+            let dy = mouse_y - transform.translation().y;
+            player.direction = dy.atan2(dx);
+
+            if !fire || player.weapon_cooldown > 0.0 {
+                continue;
+            }
+
+            player.weapon_cooldown = fire_bullet(
+                &entities,
+                &lazy,
+                &bullet_resource,
+                transform,
+                player.direction,
+                &sounds,
+                &sound_storage,
+                audio_output.as_ref().map(|o| &**o),
+                &audio_config,
+            );
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use amethyst::core::timing::Time;
+use amethyst::core::transform::Transform;
+use amethyst::ecs::{Join, Read, ReadStorage, System, WriteStorage};
+use amethyst::input::InputHandler;
+
+use crate::defender::entity::{Bullet, Player};
+
+pub struct MovementSystem;
+
+impl<'s> System<'s> for MovementSystem {
+    type SystemData = (
+        WriteStorage<'s, Transform>,
+        WriteStorage<'s, Player>,
+        ReadStorage<'s, Bullet>,
+        Read<'s, Time>,
+        Read<'s, InputHandler<String, String>>,
+    );
+
+    fn run(&mut self, (mut transforms, mut players, bullets, time, input): Self::SystemData) {
+        let dt = time.delta_seconds();
+
+        for (transform, player) in (&mut transforms, &mut players).join() {
+            let x_move = input.axis_value("move_x").unwrap_or(0.0) as f32;
+            let y_move = input.axis_value("move_y").unwrap_or(0.0) as f32;
+
+            transform.translate_x(x_move * dt * 150.0);
+            transform.translate_y(y_move * dt * 150.0);
+
+            if player.weapon_cooldown > 0.0 {
+                player.weapon_cooldown -= dt;
+            }
+        }
+
+        for (transform, bullet) in (&mut transforms, &bullets).join() {
+            transform.translate_x(bullet.dx * dt);
+            transform.translate_y(bullet.dy * dt);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use amethyst::assets::AssetStorage;
+use amethyst::audio::output::Output;
+use amethyst::audio::Source;
+use amethyst::core::transform::Transform;
+use amethyst::ecs::{Entities, Join, LazyUpdate, Read, ReadExpect, ReadStorage, System, WriteStorage};
+use amethyst::input::InputHandler;
+
+use crate::defender::audio::Sounds;
+use crate::defender::config::AudioConfig;
+use crate::defender::entity::{BulletResource, Player};
+use crate::defender::weapons::fire_bullet;
+
+pub struct ShootingSystem;
+
+impl<'s> System<'s> for ShootingSystem {
+    type SystemData = (
+        Entities<'s>,
+        ReadStorage<'s, Transform>,
+        WriteStorage<'s, Player>,
+        ReadExpect<'s, BulletResource>,
+        Read<'s, LazyUpdate>,
+        Read<'s, InputHandler<String, String>>,
+        ReadExpect<'s, Sounds>,
+        Read<'s, AssetStorage<Source>>,
+        Option<Read<'s, Output>>,
+        ReadExpect<'s, AudioConfig>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, transforms, mut players, bullet_resource, lazy, input, sounds, sound_storage, audio_output, audio_config): Self::SystemData,
+    ) {
+        let firing = input.action_is_down("fire").unwrap_or(false);
+
+        for (transform, player) in (&transforms, &mut players).join() {
+            if !firing || player.weapon_cooldown > 0.0 {
+                continue;
+            }
+
+            player.weapon_cooldown = fire_bullet(
+                &entities,
+                &lazy,
+                &bullet_resource,
+                transform,
+                player.direction,
+                &sounds,
+                &sound_storage,
+                audio_output.as_ref().map(|o| &**o),
+                &audio_config,
+            );
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use amethyst::core::transform::Transform;
+use amethyst::ecs::{Join, ReadExpect, ReadStorage, System, WriteStorage};
+use amethyst::renderer::Camera;
+
+use crate::defender::config::WorldConfig;
+use crate::defender::entity::Star;
+use crate::defender::starfield::Starfield;
+
+pub struct StarfieldSystem;
+
+impl<'s> System<'s> for StarfieldSystem {
+    type SystemData = (
+        ReadStorage<'s, Camera>,
+        ReadStorage<'s, Star>,
+        WriteStorage<'s, Transform>,
+        ReadExpect<'s, Starfield>,
+        ReadExpect<'s, WorldConfig>,
+    );
+
+    fn run(&mut self, (cameras, stars, mut transforms, starfield, world_config): Self::SystemData) {
+        let camera_x = (&cameras, &transforms)
+            .join()
+            .map(|(_, transform)| transform.translation().x)
+            .next()
+            .unwrap_or(0.0);
+
+        for (star, transform) in (&stars, &mut transforms).join() {
+            let speed = starfield.layers.get(star.layer).map(|layer| layer.speed).unwrap_or(1.0);
+            let tracked = star.base_x + camera_x * (1.0 - speed);
+            transform.set_x(tracked.rem_euclid(world_config.width));
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use amethyst::assets::AssetStorage;
+use amethyst::audio::output::Output;
+use amethyst::audio::Source;
+use amethyst::core::transform::Transform;
+use amethyst::ecs::{Entities, LazyUpdate};
+
+use crate::defender::audio::{play_laser, Sounds};
+use crate::defender::config::AudioConfig;
+use crate::defender::entity::{Bullet, BulletResource};
+
+const BULLET_SPEED: f32 = 300.0;
+const WEAPON_COOLDOWN: f32 = 0.3;
+
+/// Spawns a bullet from `origin` travelling at `direction` (radians) and
+/// plays the laser sound, returning the cooldown the firing `Player` should
+/// be set to. Shared by `ShootingSystem` (keyboard) and `MouseAimSystem`
+/// (mouse) so the two input paths can't drift out of sync.
+pub fn fire_bullet(
+    entities: &Entities,
+    lazy: &LazyUpdate,
+    bullet_resource: &BulletResource,
+    origin: &Transform,
+    direction: f32,
+    sounds: &Sounds,
+    sound_storage: &AssetStorage<Source>,
+    audio_output: Option<&Output>,
+    audio_config: &AudioConfig,
+) -> f32 {
+    play_laser(sounds, sound_storage, audio_output, audio_config);
+
+    let mut bullet_transform = Transform::default();
+    bullet_transform.set_xyz(origin.translation().x, origin.translation().y, 0.0);
+
+    let (dx, dy) = (direction.cos() * BULLET_SPEED, direction.sin() * BULLET_SPEED);
+
+    let bullet_entity = entities.create();
+    lazy.insert(bullet_entity, bullet_resource.mesh.clone());
+    lazy.insert(bullet_entity, bullet_resource.material.clone());
+    lazy.insert(bullet_entity, Bullet { dx, dy });
+    lazy.insert(bullet_entity, bullet_transform);
+
+    WEAPON_COOLDOWN
+}